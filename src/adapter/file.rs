@@ -0,0 +1,263 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+
+use serde_json::{Map, Value};
+
+use crate::StoreError;
+
+use super::{Format, StorageAdapter};
+
+/// Persists the config as a single file on disk, in whichever on-disk
+/// [`Format`] was selected — JSON, RON, or TOML — while keeping the
+/// in-memory model (and schema validation) a plain `serde_json::Value`.
+#[derive(Debug)]
+pub struct FileAdapter {
+    path: PathBuf,
+    format: Format,
+}
+
+impl FileAdapter {
+    pub fn new(path: PathBuf, format: Format) -> Self {
+        FileAdapter { path, format }
+    }
+}
+
+/// Strips `// line` and `/* block */` comments and trailing commas before
+/// a closing `]`/`}` from hand-edited JSON, leaving string literals
+/// (including ones that contain `//` or a trailing comma) untouched. This
+/// lets JSON config files stay human-editable without loosening the
+/// strict parser used for writes. RON and TOML already tolerate comments
+/// natively, so this only applies to the JSON format.
+///
+/// Comments are stripped in a first pass so a trailing comma followed by a
+/// comment (`2, // note` or `2, /* x */ ]`) is recognized as trailing once
+/// the comment is out of the way, rather than being kept because the comma
+/// lookahead saw a `/` instead of the closing bracket.
+fn strip_comments_and_trailing_commas(input: &str) -> String {
+    strip_trailing_commas(&strip_comments(input))
+}
+
+fn strip_comments(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            if c == '\\' {
+                if let Some(escaped) = chars.next() {
+                    out.push(escaped);
+                }
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_string = true;
+                out.push(c);
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                while let Some(&next) = chars.peek() {
+                    if next == '\n' {
+                        break;
+                    }
+                    chars.next();
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                while let Some(next) = chars.next() {
+                    if next == '*' && chars.peek() == Some(&'/') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+fn strip_trailing_commas(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            if c == '\\' {
+                if let Some(escaped) = chars.next() {
+                    out.push(escaped);
+                }
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_string = true;
+                out.push(c);
+            }
+            ',' => {
+                // look ahead past whitespace for a closing bracket, in
+                // which case this trailing comma is dropped
+                let mut lookahead = chars.clone();
+                let mut only_whitespace_until_close = false;
+                while let Some(&next) = lookahead.peek() {
+                    if next.is_whitespace() {
+                        lookahead.next();
+                        continue;
+                    }
+                    only_whitespace_until_close = next == ']' || next == '}';
+                    break;
+                }
+                if !only_whitespace_until_close {
+                    out.push(c);
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// TOML has no representation for `null` and requires every scalar key in a
+/// table to come before any table-valued key at the same level (the `toml`
+/// crate rejects the reverse order with `ValueAfterTable`). `serde_json::Map`
+/// is sorted alphabetically, so a config like `{"server": {...}, "version":
+/// 1}` would hit that ordering rule, and a `null` left over from
+/// `default_config` or a merge would fail to serialize at all. This walks a
+/// `Value` and produces one that `toml::to_string_pretty` can always handle:
+/// `null`s are dropped (TOML's closest notion of "absent") and each object's
+/// entries are re-ordered with scalars first.
+fn toml_safe(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut scalars = Map::new();
+            let mut tables = Map::new();
+            for (key, v) in map {
+                if v.is_null() {
+                    continue;
+                }
+                let v = toml_safe(v);
+                if v.is_object() {
+                    tables.insert(key.clone(), v);
+                } else {
+                    scalars.insert(key.clone(), v);
+                }
+            }
+            scalars.extend(tables);
+            Value::Object(scalars)
+        }
+        Value::Array(items) => Value::Array(items.iter().map(toml_safe).collect()),
+        leaf => leaf.clone(),
+    }
+}
+
+impl StorageAdapter for FileAdapter {
+    fn load(&self) -> Result<Value, StoreError> {
+        if !self.path.exists() {
+            return Ok(Value::Object(serde_json::Map::new()));
+        }
+
+        let mut raw = String::new();
+        File::open(&self.path)
+            .and_then(|mut file| file.read_to_string(&mut raw))
+            .map_err(|source| StoreError::Io {
+                path: self.path.clone(),
+                source,
+            })?;
+
+        match self.format {
+            Format::Json => serde_json::from_str(&strip_comments_and_trailing_commas(&raw))
+                .map_err(|source| StoreError::ConfigParse {
+                    path: self.path.clone(),
+                    source,
+                }),
+            Format::Ron => ron::de::from_str(&raw).map_err(|e| {
+                StoreError::Adapter(format!("failed to parse {:?} as RON: {}", self.path, e))
+            }),
+            Format::Toml => toml::from_str(&raw).map_err(|e| {
+                StoreError::Adapter(format!("failed to parse {:?} as TOML: {}", self.path, e))
+            }),
+        }
+    }
+
+    fn persist(&self, value: &Value) -> Result<(), StoreError> {
+        let contents = match self.format {
+            Format::Json => serde_json::to_string_pretty(value)
+                .map_err(|e| StoreError::Adapter(format!("failed to serialize config: {}", e)))?,
+            Format::Ron => ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())
+                .map_err(|e| {
+                    StoreError::Adapter(format!("failed to serialize config as RON: {}", e))
+                })?,
+            Format::Toml => toml::to_string_pretty(&toml_safe(value)).map_err(|e| {
+                StoreError::Adapter(format!("failed to serialize config as TOML: {}", e))
+            })?,
+        };
+
+        // Write to a sibling temp file and rename it into place so a crash
+        // mid-write can never leave a truncated config behind.
+        let tmp_path = self.path.with_extension(format!("{}.tmp", self.format.extension()));
+        std::fs::write(&tmp_path, contents).map_err(|source| StoreError::Io {
+            path: tmp_path.clone(),
+            source,
+        })?;
+        std::fs::rename(&tmp_path, &self.path).map_err(|source| StoreError::Io {
+            path: self.path.clone(),
+            source,
+        })
+    }
+
+    fn name(&self) -> &str {
+        self.format.extension()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_line_and_block_comments() {
+        let input = "{\n  \"a\": 1, // note\n  \"b\": /* inline */ 2\n}";
+        let stripped = strip_comments_and_trailing_commas(input);
+        let value: Value = serde_json::from_str(&stripped).unwrap();
+        assert_eq!(value, serde_json::json!({"a": 1, "b": 2}));
+    }
+
+    #[test]
+    fn drops_trailing_comma_followed_by_line_comment() {
+        let input = "{\n  \"a\": 1,\n  \"b\": 2, // note\n}";
+        let stripped = strip_comments_and_trailing_commas(input);
+        let value: Value = serde_json::from_str(&stripped).unwrap();
+        assert_eq!(value, serde_json::json!({"a": 1, "b": 2}));
+    }
+
+    #[test]
+    fn drops_trailing_comma_followed_by_block_comment() {
+        let input = "[1, 2, /* x */ ]";
+        let stripped = strip_comments_and_trailing_commas(input);
+        let value: Value = serde_json::from_str(&stripped).unwrap();
+        assert_eq!(value, serde_json::json!([1, 2]));
+    }
+
+    #[test]
+    fn leaves_commas_and_comment_markers_inside_strings_untouched() {
+        let input = r#"{"a": "b, // not a comment, c"}"#;
+        let stripped = strip_comments_and_trailing_commas(input);
+        let value: Value = serde_json::from_str(&stripped).unwrap();
+        assert_eq!(value, serde_json::json!({"a": "b, // not a comment, c"}));
+    }
+}
@@ -0,0 +1,32 @@
+use serde_json::Value;
+
+use crate::StoreError;
+
+use super::StorageAdapter;
+
+/// An adapter that never touches disk. `load` always starts from an empty
+/// object and `persist` is a no-op; the config only lives as long as the
+/// `Store` does. Handy for tests and ephemeral apps that want the `Store`
+/// API without a file on disk.
+#[derive(Debug, Default)]
+pub struct MemoryAdapter;
+
+impl MemoryAdapter {
+    pub fn new() -> Self {
+        MemoryAdapter
+    }
+}
+
+impl StorageAdapter for MemoryAdapter {
+    fn load(&self) -> Result<Value, StoreError> {
+        Ok(Value::Object(serde_json::Map::new()))
+    }
+
+    fn persist(&self, _value: &Value) -> Result<(), StoreError> {
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "memory"
+    }
+}
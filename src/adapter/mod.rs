@@ -0,0 +1,82 @@
+mod file;
+mod memory;
+mod sqlite;
+
+use serde_json::Value;
+
+pub use file::FileAdapter;
+pub use memory::MemoryAdapter;
+pub use sqlite::SqliteAdapter;
+
+use crate::StoreError;
+
+/// A pluggable persistence backend for a [`Store`](crate::Store).
+///
+/// Implementors are responsible for turning whatever they persist into a
+/// single JSON [`Value`] on [`load`](StorageAdapter::load) and for writing
+/// that `Value` back out on [`persist`](StorageAdapter::persist). The
+/// `Store` itself never touches the underlying medium directly; it always
+/// goes through an adapter so that callers can swap a file for a database
+/// (or nothing at all) without changing how `get`/`set`/`has`/`delete` work.
+pub trait StorageAdapter: std::fmt::Debug {
+    /// Load the persisted config, returning an empty object if there is
+    /// nothing to load yet.
+    fn load(&self) -> Result<Value, StoreError>;
+
+    /// Persist the full config.
+    fn persist(&self, value: &Value) -> Result<(), StoreError>;
+
+    /// A short human-readable name for the backend, used in `Display`/debug
+    /// output (e.g. "json", "memory", "sqlite").
+    fn name(&self) -> &str;
+}
+
+/// On-disk serialization used by `Backend::File`. The in-memory model and
+/// schema validation always work against a plain `serde_json::Value`;
+/// this only controls how that value is read from and written to disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Pretty-printed JSON (the original behavior).
+    Json,
+    /// [RON](https://github.com/ron-rs/ron), useful for richer literals
+    /// (enums, tuples) than JSON allows.
+    Ron,
+    /// TOML, useful for config files humans edit directly.
+    Toml,
+}
+
+impl Format {
+    /// The file extension conventionally used for this format. `Store::new`
+    /// names the config file `config.<extension>`, and `FileAdapter`
+    /// reports it as its backend name.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Format::Json => "json",
+            Format::Ron => "ron",
+            Format::Toml => "toml",
+        }
+    }
+
+    /// Infers a `Format` from a file extension, defaulting to `Json` for
+    /// anything unrecognized.
+    pub fn from_extension(extension: &str) -> Format {
+        match extension {
+            "ron" => Format::Ron,
+            "toml" => Format::Toml,
+            _ => Format::Json,
+        }
+    }
+}
+
+/// Selects which [`StorageAdapter`] a [`Store`](crate::Store) is backed by.
+#[derive(Debug, Clone)]
+pub enum Backend {
+    /// A single file on disk, serialized in the given [`Format`].
+    File(Format),
+    /// Nothing is written; the config only lives in memory for the
+    /// lifetime of the `Store`. Useful for tests and ephemeral apps.
+    Memory,
+    /// A SQLite database storing flattened dotted keys as rows in a
+    /// `settings(key TEXT PRIMARY KEY, value TEXT)` table.
+    Sqlite,
+}
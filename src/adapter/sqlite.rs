@@ -0,0 +1,174 @@
+use std::path::PathBuf;
+
+use rusqlite::{params, Connection};
+use serde_json::{Map, Value};
+
+use crate::StoreError;
+
+use super::StorageAdapter;
+
+/// Stores the config as flattened dotted keys in a SQLite `settings` table
+/// (`key TEXT PRIMARY KEY, value TEXT`), where `value` is the JSON-encoded
+/// leaf value. This gives applications a queryable/transactional config
+/// store instead of a single file.
+#[derive(Debug)]
+pub struct SqliteAdapter {
+    path: PathBuf,
+}
+
+impl SqliteAdapter {
+    pub fn new(path: PathBuf) -> Result<Self, StoreError> {
+        let adapter = SqliteAdapter { path };
+        adapter.connection()?.execute(
+            "CREATE TABLE IF NOT EXISTS settings (key TEXT PRIMARY KEY, value TEXT NOT NULL)",
+            [],
+        )
+        .map_err(|e| StoreError::Adapter(format!("failed to create settings table: {}", e)))?;
+        Ok(adapter)
+    }
+
+    fn connection(&self) -> Result<Connection, StoreError> {
+        Connection::open(&self.path)
+            .map_err(|e| StoreError::Adapter(format!("failed to open {:?}: {}", self.path, e)))
+    }
+}
+
+/// Flattens a nested JSON object into dotted-key/value pairs, e.g.
+/// `{"a": {"b": 1}}` becomes `[("a.b", 1)]`. A nested empty object is kept
+/// as its own sentinel row (e.g. `{"a": {}}` becomes `[("a", {})]`) so it
+/// round-trips instead of silently vanishing; the top-level config itself
+/// being `{}` still produces zero rows, which `unflatten` already turns
+/// back into `{}`.
+fn flatten(prefix: &str, value: &Value, out: &mut Vec<(String, Value)>) {
+    match value {
+        Value::Object(map) if map.is_empty() => {
+            if !prefix.is_empty() {
+                out.push((prefix.to_string(), Value::Object(Map::new())));
+            }
+        }
+        Value::Object(map) => {
+            for (key, v) in map {
+                let full_key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten(&full_key, v, out);
+            }
+        }
+        leaf => out.push((prefix.to_string(), leaf.clone())),
+    }
+}
+
+/// Inverse of [`flatten`]: rebuilds a nested JSON object from dotted keys.
+fn unflatten(rows: Vec<(String, Value)>) -> Value {
+    let mut root = Map::new();
+    for (key, value) in rows {
+        let mut current = &mut root;
+        let mut parts = key.split('.').peekable();
+        while let Some(part) = parts.next() {
+            if parts.peek().is_none() {
+                current.insert(part.to_string(), value);
+                break;
+            }
+            current = current
+                .entry(part.to_string())
+                .or_insert_with(|| Value::Object(Map::new()))
+                .as_object_mut()
+                .expect("flattened key collides with a leaf value");
+        }
+    }
+    Value::Object(root)
+}
+
+impl StorageAdapter for SqliteAdapter {
+    fn load(&self) -> Result<Value, StoreError> {
+        let conn = self.connection()?;
+        let mut stmt = conn
+            .prepare("SELECT key, value FROM settings")
+            .map_err(|e| StoreError::Adapter(format!("failed to query settings: {}", e)))?;
+        let rows = stmt
+            .query_map([], |row| {
+                let key: String = row.get(0)?;
+                let raw: String = row.get(1)?;
+                Ok((key, raw))
+            })
+            .map_err(|e| StoreError::Adapter(format!("failed to read settings: {}", e)))?;
+
+        let mut pairs = Vec::new();
+        for row in rows {
+            let (key, raw) =
+                row.map_err(|e| StoreError::Adapter(format!("failed to read row: {}", e)))?;
+            let value: Value = serde_json::from_str(&raw)
+                .map_err(|e| StoreError::Adapter(format!("failed to parse stored value: {}", e)))?;
+            pairs.push((key, value));
+        }
+
+        Ok(unflatten(pairs))
+    }
+
+    fn persist(&self, value: &Value) -> Result<(), StoreError> {
+        let mut conn = self.connection()?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| StoreError::Adapter(format!("failed to start transaction: {}", e)))?;
+        tx.execute("DELETE FROM settings", [])
+            .map_err(|e| StoreError::Adapter(format!("failed to clear settings: {}", e)))?;
+
+        let mut pairs = Vec::new();
+        flatten("", value, &mut pairs);
+        for (key, leaf) in pairs {
+            let raw = serde_json::to_string(&leaf)
+                .map_err(|e| StoreError::Adapter(format!("failed to serialize {}: {}", key, e)))?;
+            tx.execute(
+                "INSERT INTO settings (key, value) VALUES (?1, ?2)",
+                params![key, raw],
+            )
+            .map_err(|e| StoreError::Adapter(format!("failed to write {}: {}", key, e)))?;
+        }
+
+        tx.commit()
+            .map_err(|e| StoreError::Adapter(format!("failed to commit settings: {}", e)))
+    }
+
+    fn name(&self) -> &str {
+        "sqlite"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flatten_unflatten_round_trip() {
+        let value = serde_json::json!({
+            "a": {"b": 1, "c": {"d": 2}},
+            "e": true,
+        });
+
+        let mut pairs = Vec::new();
+        flatten("", &value, &mut pairs);
+        assert_eq!(unflatten(pairs), value);
+    }
+
+    #[test]
+    fn flatten_preserves_nested_empty_object() {
+        let value = serde_json::json!({"a": {}});
+
+        let mut pairs = Vec::new();
+        flatten("", &value, &mut pairs);
+        assert_eq!(pairs, vec![("a".to_string(), serde_json::json!({}))]);
+        assert_eq!(unflatten(pairs), value);
+    }
+
+    #[test]
+    fn flatten_empty_root_produces_no_rows() {
+        let value = serde_json::json!({});
+
+        let mut pairs = Vec::new();
+        flatten("", &value, &mut pairs);
+        assert!(pairs.is_empty());
+        assert_eq!(unflatten(pairs), value);
+    }
+}
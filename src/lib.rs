@@ -1,3 +1,5 @@
+mod adapter;
+
 use std::fmt;
 use std::fs::File;
 use std::io::BufReader;
@@ -5,14 +7,81 @@ use std::path::PathBuf;
 
 use directories::ProjectDirs;
 use jsonschema::JSONSchema;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use serde_json::Value;
 use thiserror::Error;
 
-fn default_config(schema: &JSONSchema) -> Value {
-    return Value::from("value");
+pub use adapter::{Backend, FileAdapter, Format, MemoryAdapter, SqliteAdapter, StorageAdapter};
+
+// Recursively build a default config value from a raw (uncompiled) JSON
+// Schema document. An explicit `"default"` wins wherever it appears;
+// otherwise objects are rebuilt property-by-property (a property is only
+// included if it has a default somewhere underneath it or is listed as
+// `"required"`), arrays default to `[]`, and scalar types fall back to a
+// type-appropriate zero value.
+fn default_config(schema: &Value) -> Value {
+    if let Some(default) = schema.get("default") {
+        return default.clone();
+    }
+
+    match schema.get("type").and_then(Value::as_str) {
+        Some("object") => {
+            let required: Vec<&str> = schema
+                .get("required")
+                .and_then(Value::as_array)
+                .map(|values| values.iter().filter_map(Value::as_str).collect())
+                .unwrap_or_default();
+
+            let mut object = serde_json::Map::new();
+            if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+                for (name, property_schema) in properties {
+                    let is_object_with_default =
+                        property_schema.get("type").and_then(Value::as_str) == Some("object")
+                            && has_default(property_schema);
+                    if required.contains(&name.as_str())
+                        || property_schema.get("default").is_some()
+                        || is_object_with_default
+                    {
+                        object.insert(name.clone(), default_config(property_schema));
+                    }
+                }
+            }
+            Value::Object(object)
+        }
+        Some("array") => Value::Array(Vec::new()),
+        Some("string") => Value::from(""),
+        Some("integer") => Value::from(0),
+        Some("number") => Value::from(0.0),
+        Some("boolean") => Value::from(false),
+        _ => Value::Null,
+    }
+}
+
+// Whether a schema node (or anything nested inside it) declares an
+// explicit `"default"`, used to decide whether an optional object property
+// is worth including in a generated default config.
+fn has_default(schema: &Value) -> bool {
+    if schema.get("default").is_some() {
+        return true;
+    }
+    if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+        return properties.values().any(has_default);
+    }
+    false
+}
+
+// Walks a raw schema document following a dotted path through nested
+// `"properties"`, returning the schema node at that path if one exists.
+fn schema_at_path<'a>(schema: &'a Value, keys: &str) -> Option<&'a Value> {
+    let mut current = schema;
+    for key in keys.split(".") {
+        current = current.get("properties")?.get(key)?;
+    }
+    Some(current)
 }
 
-#[derive(Error, Debug, PartialEq)]
+#[derive(Error, Debug)]
 pub enum StoreError {
     #[error("failed to initialize store")]
     InitError,
@@ -22,104 +91,212 @@ pub enum StoreError {
     InvalidKey,
     #[error("invalid key-value delete")]
     InvalidDelete,
+    #[error("storage backend error: {0}")]
+    Adapter(String),
+    #[error("type mismatch: {0}")]
+    TypeMismatch(String),
+    #[error("I/O error at {path:?}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("failed to parse schema {path:?}: {source}")]
+    SchemaParse {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("failed to compile schema {path:?}: {reason}")]
+    SchemaCompile { path: PathBuf, reason: String },
+    #[error("failed to parse config {path:?}: {source}")]
+    ConfigParse {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("invalid override path: {0:?}")]
+    InvalidOverridePath(PathBuf),
+}
+
+/// Deep-merges `overlay` into `base`, in place: for two objects, recurses
+/// key-by-key and overwrites `base` only with non-null leaves from
+/// `overlay`, so a higher layer can set one field without wiping its
+/// siblings. An explicit `null` in `overlay` is treated as "inherit" and
+/// leaves `base` untouched.
+pub fn merge_non_null(base: &mut Value, overlay: &Value) {
+    match (base, overlay) {
+        (Value::Object(base_map), Value::Object(overlay_map)) => {
+            for (key, overlay_value) in overlay_map {
+                if overlay_value.is_null() {
+                    continue;
+                }
+                match base_map.get_mut(key) {
+                    Some(base_value) => merge_non_null(base_value, overlay_value),
+                    None => {
+                        let mut inserted = if overlay_value.is_object() {
+                            Value::Object(serde_json::Map::new())
+                        } else {
+                            Value::Null
+                        };
+                        merge_non_null(&mut inserted, overlay_value);
+                        base_map.insert(key.clone(), inserted);
+                    }
+                }
+            }
+        }
+        (base_slot, overlay_value) => {
+            if !overlay_value.is_null() {
+                *base_slot = overlay_value.clone();
+            }
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct Store {
     path: PathBuf,
     schema: Option<JSONSchema>,
+    schema_value: Option<Value>,
+    /// The effective, merged view: every read-only layer in `layer_values`
+    /// deep-merged in order, then `user_config` merged on top.
     config: Value,
+    /// The top (user) layer on its own; this is what `set`/`delete`
+    /// mutate and what gets persisted through `adapter`.
+    user_config: Value,
+    /// Read-only lower-precedence layers (e.g. bundled defaults, a
+    /// system-wide file), lowest precedence first.
+    layer_values: Vec<Value>,
+    adapter: Box<dyn StorageAdapter>,
+    /// When `false`, `set`/`delete`/`reset` only update `user_config` in
+    /// memory; callers must invoke [`Store::save`] explicitly to persist.
+    autosave: bool,
 }
 
 impl fmt::Display for Store {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.path.to_string_lossy())
+        write!(f, "{} ({})", self.path.to_string_lossy(), self.adapter.name())
     }
 }
 
+// Deep-merges a list of layers (lowest precedence first) into a single
+// effective config value.
+fn merge_layers(layer_values: &[Value], user_config: &Value) -> Value {
+    let mut merged = Value::Object(serde_json::Map::new());
+    for layer in layer_values {
+        merge_non_null(&mut merged, layer);
+    }
+    merge_non_null(&mut merged, user_config);
+    merged
+}
+
 impl Store {
     pub fn new(
         company_name: String,
         app_name: String,
         schema_path: Option<PathBuf>,
         path_override: Option<PathBuf>,
+        backend: Backend,
+        layer_paths: Vec<PathBuf>,
+        autosave: bool,
     ) -> Result<Store, StoreError> {
         // Initialize schema as None, then load if a path was provided
         let mut schema: Option<JSONSchema> = None;
+        let mut schema_value: Option<Value> = None;
         if let Some(schema_path) = schema_path {
-            schema = Some(
-                JSONSchema::compile(
-                    &serde_json::from_reader(BufReader::new(
-                        File::open(&schema_path).expect("Failed to open file"),
-                    ))
-                    .unwrap(),
-                )
-                .unwrap(),
-            );
+            let file = File::open(&schema_path).map_err(|source| StoreError::Io {
+                path: schema_path.clone(),
+                source,
+            })?;
+            let raw: Value =
+                serde_json::from_reader(BufReader::new(file)).map_err(|source| {
+                    StoreError::SchemaParse {
+                        path: schema_path.clone(),
+                        source,
+                    }
+                })?;
+            let compiled = JSONSchema::compile(&raw).map_err(|e| StoreError::SchemaCompile {
+                path: schema_path.clone(),
+                reason: e.to_string(),
+            })?;
+            schema = Some(compiled);
+            schema_value = Some(raw);
         }
 
+        let file_name = match &backend {
+            Backend::Sqlite => "config.db".to_string(),
+            Backend::File(format) => format!("config.{}", format.extension()),
+            Backend::Memory => "config.json".to_string(),
+        };
+
         let mut config_path: PathBuf = PathBuf::new();
 
         // If a path override was provided, use that for config path
         if let Some(path) = path_override {
             // make sure the path is a directory that exists
             if path.is_dir() && path.exists() {
-                config_path = path.join("config.json");
+                config_path = path.join(file_name);
             } else {
-                panic!("invalid override path");
+                return Err(StoreError::InvalidOverridePath(path));
             }
         } else {
-            // See if a config json file exists in the UserData directory for the provided app name
+            // See if a config file exists in the UserData directory for the provided app name
             // Get the config directory
             if let Some(proj_dirs) = ProjectDirs::from("com", &company_name, &app_name) {
-                config_path = PathBuf::from(proj_dirs.config_dir()).join("config.json");
+                config_path = PathBuf::from(proj_dirs.config_dir()).join(file_name);
             }
         }
 
-        // If the file exists, load it
-        if config_path.exists() {
-            let mut config: Value = serde_json::from_reader(BufReader::new(
-                File::open(&config_path).expect("Failed to open file"),
-            ))
-            .unwrap();
-            // Validate the config against the schema
-            if let Some(s) = &schema {
-                // if the config passes validation, return the Store
-                if s.is_valid(&config) {
-                    return Ok(Store {
-                        path: config_path,
-                        config,
-                        schema,
-                    });
-                } else {
-                    // otherwise, generate a default config and return a store
-                    config = default_config(s);
-                    return Ok(Store {
-                        path: config_path,
-                        config,
-                        schema,
-                    });
-                }
-            } else {
-                // if no schema, just return the store
-                return Ok(Store {
-                    path: config_path,
-                    config,
-                    schema,
-                });
+        // Captured before constructing the adapter: `SqliteAdapter::new`
+        // creates `config_path` as a side effect of opening the database,
+        // so checking existence afterwards would always see `true`.
+        let existed = config_path.exists();
+
+        let adapter: Box<dyn StorageAdapter> = match backend {
+            Backend::File(format) => Box::new(FileAdapter::new(config_path.clone(), format)),
+            Backend::Memory => Box::new(MemoryAdapter::new()),
+            Backend::Sqlite => Box::new(SqliteAdapter::new(config_path.clone())?),
+        };
+
+        let mut user_config: Value = adapter.load()?;
+
+        // Validate the config against the schema
+        if let Some(s) = &schema {
+            let raw = schema_value.as_ref().expect("schema_value set alongside schema");
+            if !existed || !s.is_valid(&user_config) {
+                // the stored config is missing or doesn't match the schema;
+                // regenerate defaults from the schema
+                user_config = default_config(raw);
             }
-        } else if let Some(s) = &schema {
-            // if no config exists but there is a schema, create a default config and return the store
-            let config: Value = default_config(s);
-            return Ok(Store {
-                path: config_path,
-                config,
-                schema,
-            });
-        } else {
+        } else if !existed && user_config == Value::Object(serde_json::Map::new()) {
             // if there is no config and no schema, error
             return Err(StoreError::InitError);
         }
+
+        // Load the read-only layers beneath the user config (e.g. bundled
+        // defaults, a system-wide file), lowest precedence first.
+        let mut layer_values: Vec<Value> = Vec::new();
+        for layer_path in &layer_paths {
+            let format = layer_path
+                .extension()
+                .and_then(std::ffi::OsStr::to_str)
+                .map(Format::from_extension)
+                .unwrap_or(Format::Json);
+            layer_values.push(FileAdapter::new(layer_path.clone(), format).load()?);
+        }
+
+        let config = merge_layers(&layer_values, &user_config);
+
+        Ok(Store {
+            path: config_path,
+            config,
+            user_config,
+            layer_values,
+            schema,
+            schema_value,
+            adapter,
+            autosave,
+        })
     }
 
     // Get a value from the store, or the default if it doesn't exist, or error if it isn't a valid key
@@ -136,40 +313,77 @@ impl Store {
         return Ok(current_value.clone());
     }
 
-    // Set a key-value pair
+    // Get a value from the store and deserialize it into `T`, instead of
+    // handing back a raw `Value` for the caller to match on.
+    pub fn get_as<T: DeserializeOwned>(&self, keys: String) -> Result<T, StoreError> {
+        let value = self.get(keys)?;
+        serde_json::from_value(value)
+            .map_err(|e| StoreError::TypeMismatch(format!("failed to deserialize value: {}", e)))
+    }
+
+    // Set a key-value pair. Only the top (user) layer is mutated and
+    // persisted; the read-only layers underneath are left untouched. A key
+    // that only exists in a lower layer is still a valid override target:
+    // any missing intermediate objects are created in the user layer copy
+    // so the merged view can win out over the layer below it.
     pub fn set(&mut self, keys: String, value: Value) -> Result<(), StoreError> {
-        // make a copy of the config
-        let mut config = self.config.clone();
-        let mut current_value: &mut Value = &mut config;
-        // // update the value in the config copy
-        for key in keys.split(".") {
-            if let Some(v) = current_value.get_mut(key) {
-                current_value = v;
-            } else {
-                return Err(StoreError::InvalidKey);
+        if !self.has(keys.clone()) {
+            return Err(StoreError::InvalidKey);
+        }
+
+        // make a copy of the user layer
+        let mut user_config = self.user_config.clone();
+        let mut current_value: &mut Value = &mut user_config;
+        // update the value in the copy, creating any path segment that's
+        // only present in a lower layer
+        let mut segments = keys.split(".").peekable();
+        while let Some(key) = segments.next() {
+            let entry = current_value
+                .as_object_mut()
+                .ok_or(StoreError::InvalidKey)?
+                .entry(key.to_string())
+                .or_insert_with(|| Value::Object(serde_json::Map::new()));
+            if segments.peek().is_none() {
+                *entry = value;
+                break;
             }
+            current_value = entry;
+        }
+
+        let config = merge_layers(&self.layer_values, &user_config);
+
+        // with no schema there is nothing to validate against, so the
+        // change is applied as is
+        if self.schema.is_none() {
+            self.user_config = user_config.clone();
+            self.config = config.clone();
         }
-        *current_value = value;
 
         // replace the old config with the new one if it passes validation
         if let Some(schema) = &self.schema {
             if schema.is_valid(&config) {
+                self.user_config = user_config;
                 self.config = config;
             } else {
                 return Err(StoreError::InvalidSet);
             }
         }
 
-        // write the config to file
-        std::fs::write(
-            &self.path,
-            serde_json::to_string_pretty(&self.config).unwrap(),
-        )
-        .unwrap();
+        if self.autosave {
+            self.save()?;
+        }
 
         return Ok(());
     }
 
+    // Serialize `value` and set it at `keys`, instead of requiring the
+    // caller to build a `Value` by hand.
+    pub fn set_typed<T: Serialize>(&mut self, keys: String, value: T) -> Result<(), StoreError> {
+        let value = serde_json::to_value(value)
+            .map_err(|e| StoreError::TypeMismatch(format!("failed to serialize value: {}", e)))?;
+        self.set(keys, value)
+    }
+
     // Check if a key exists
     pub fn has(&self, keys: String) -> bool {
         let mut current_value: &Value = &self.config;
@@ -183,47 +397,113 @@ impl Store {
         return true;
     }
 
-    // Delete an object
+    // Delete an object. Only the top (user) layer is mutated and
+    // persisted; the read-only layers underneath are left untouched. A key
+    // that only exists in a lower layer is still a valid delete target: if
+    // the user layer doesn't hold it, there's simply nothing there to
+    // remove, rather than that being an error.
     pub fn delete(&mut self, keys: String) -> Result<(), StoreError> {
-        // make a copy of the config
-        let mut config = self.config.clone();
-        let mut current_value: &mut Value = &mut config;
-        // // update the value in the config copy
+        if !self.has(keys.clone()) {
+            return Err(StoreError::InvalidKey);
+        }
+
+        // make a copy of the user layer
+        let mut user_config = self.user_config.clone();
+        let mut current_value: &mut Value = &mut user_config;
+        // update the value in the copy, creating any path segment that's
+        // only present in a lower layer
         let mut keys = keys.split(".").peekable();
         while let Some(key) = keys.next() {
             if keys.peek().is_none() {
-                if let Some(_deleted) = current_value.as_object_mut().unwrap().remove_entry(key) {
-                } else {
-                    return Err(StoreError::InvalidKey);
+                if let Some(object) = current_value.as_object_mut() {
+                    object.remove_entry(key);
                 }
-            } else if let Some(v) = current_value.get_mut(key) {
-                current_value = v;
-            } else {
-                return Err(StoreError::InvalidKey);
+                break;
             }
+            current_value = current_value
+                .as_object_mut()
+                .ok_or(StoreError::InvalidKey)?
+                .entry(key.to_string())
+                .or_insert_with(|| Value::Object(serde_json::Map::new()));
+        }
+
+        let config = merge_layers(&self.layer_values, &user_config);
+
+        // with no schema there is nothing to validate against, so the
+        // change is applied as is
+        if self.schema.is_none() {
+            self.user_config = user_config.clone();
+            self.config = config.clone();
         }
 
         if let Some(schema) = &self.schema {
             if schema.is_valid(&config) {
+                self.user_config = user_config;
                 self.config = config;
             } else {
                 return Err(StoreError::InvalidDelete);
             }
         }
 
-        // write the config to file
-        std::fs::write(
-            &self.path,
-            serde_json::to_string_pretty(&self.config).unwrap(),
-        )
-        .unwrap();
+        if self.autosave {
+            self.save()?;
+        }
 
         return Ok(());
     }
 
-    // Reset keys to their default values as defined in the schema
-    pub fn reset(key: Option<String>) -> Result<(), StoreError> {
-        return Err(StoreError::InvalidKey);
+    // Persist the current user layer, for callers running with
+    // `autosave` disabled who want to batch up several mutations and
+    // write them out once.
+    pub fn save(&self) -> Result<(), StoreError> {
+        self.adapter.persist(&self.user_config)
+    }
+
+    // Reset keys to their default values as defined in the schema. `None`
+    // regenerates the whole config; `Some("a.b")` replaces just the
+    // subtree at that dotted path with its schema default.
+    pub fn reset(&mut self, key: Option<String>) -> Result<(), StoreError> {
+        let raw = self.schema_value.as_ref().ok_or(StoreError::InitError)?;
+
+        // reset only ever touches the top (user) layer
+        let user_config = match key {
+            None => default_config(raw),
+            Some(keys) => {
+                let node = schema_at_path(raw, &keys).ok_or(StoreError::InvalidKey)?;
+                let default = default_config(node);
+
+                let mut user_config = self.user_config.clone();
+                let mut current_value: &mut Value = &mut user_config;
+                let mut segments = keys.split(".").peekable();
+                while let Some(segment) = segments.next() {
+                    if segments.peek().is_none() {
+                        current_value
+                            .as_object_mut()
+                            .ok_or(StoreError::InvalidKey)?
+                            .insert(segment.to_string(), default);
+                        break;
+                    }
+                    current_value = current_value
+                        .get_mut(segment)
+                        .ok_or(StoreError::InvalidKey)?;
+                }
+                user_config
+            }
+        };
+
+        let config = merge_layers(&self.layer_values, &user_config);
+        if let Some(schema) = &self.schema {
+            if !schema.is_valid(&config) {
+                return Err(StoreError::InvalidSet);
+            }
+        }
+        self.user_config = user_config;
+        self.config = config;
+        if self.autosave {
+            self.save()?;
+        }
+
+        Ok(())
     }
 }
 
@@ -235,33 +515,35 @@ mod tests {
 
     fn create_test_config() -> Store {
         let schema_path = PathBuf::from("tests/config.schema.json");
-        let schema = Some(
-            JSONSchema::compile(
-                &serde_json::from_reader(BufReader::new(
-                    File::open(&schema_path).expect("Failed to open file"),
-                ))
-                .unwrap(),
-            )
-            .unwrap(),
-        );
+        let raw: Value = serde_json::from_reader(BufReader::new(
+            File::open(&schema_path).expect("Failed to open file"),
+        ))
+        .unwrap();
+        let schema = Some(JSONSchema::compile(&raw).unwrap());
+        let config = json!({
+          "aSetting": {
+            "i": 400,
+            "j": 250,
+            "k": 215
+          },
+          "anotherSetting": {
+            "x": 2.0,
+            "y": 1.0,
+            "z": 0.5
+          },
+          "deletableSetting": {
+            "set": 0.1
+          }
+        });
         return Store {
             path: (PathBuf::from("tests/config.json")),
             schema: (schema),
-            config: (json!({
-              "aSetting": {
-                "i": 400,
-                "j": 250,
-                "k": 215
-              },
-              "anotherSetting": {
-                "x": 2.0,
-                "y": 1.0,
-                "z": 0.5
-              },
-              "deletableSetting": {
-                "set": 0.1
-              }
-            })),
+            schema_value: Some(raw),
+            adapter: Box::new(MemoryAdapter::new()),
+            user_config: config.clone(),
+            layer_values: Vec::new(),
+            config,
+            autosave: true,
         };
     }
 
@@ -327,8 +609,7 @@ mod tests {
                 serde_json::to_value(-10).unwrap(),
             )
             .unwrap_err();
-        let expected = StoreError::InvalidSet;
-        assert_eq!(result, expected);
+        assert!(matches!(result, StoreError::InvalidSet));
     }
     #[test]
     fn test_delete() {
@@ -336,11 +617,105 @@ mod tests {
 
         // test for deleting a key that is required
         let result = store.delete(String::from("aSetting.i")).unwrap_err();
-        let expected = StoreError::InvalidDelete;
-        assert_eq!(result, expected);
+        assert!(matches!(result, StoreError::InvalidDelete));
 
         // test for deleting an optional key
         store.delete(String::from("deletableSetting")).unwrap();
         assert!(!store.has(String::from("deletableSetting")));
     }
+
+    #[test]
+    fn test_merge_non_null_overlay_precedence() {
+        let mut base = json!({
+            "a": 1,
+            "nested": {"x": 1, "y": 2},
+            "only_in_base": true,
+        });
+        let overlay = json!({
+            "a": 2,
+            "nested": {"x": 10, "z": 3},
+            "only_in_overlay": "new",
+        });
+
+        merge_non_null(&mut base, &overlay);
+
+        assert_eq!(
+            base,
+            json!({
+                "a": 2,
+                "nested": {"x": 10, "y": 2, "z": 3},
+                "only_in_base": true,
+                "only_in_overlay": "new",
+            })
+        );
+    }
+
+    #[test]
+    fn test_merge_non_null_explicit_null_means_inherit() {
+        let mut base = json!({"a": 1, "b": 2});
+        let overlay = json!({"a": Value::Null, "b": 5});
+
+        merge_non_null(&mut base, &overlay);
+
+        assert_eq!(base, json!({"a": 1, "b": 5}));
+    }
+
+    #[test]
+    fn test_merge_layers_precedence_lowest_to_highest() {
+        let layer_values = vec![
+            json!({"theme": "light", "nested": {"x": 1}}),
+            json!({"theme": "dark"}),
+        ];
+        let user_config = json!({"nested": {"y": 2}});
+
+        let merged = merge_layers(&layer_values, &user_config);
+
+        assert_eq!(
+            merged,
+            json!({"theme": "dark", "nested": {"x": 1, "y": 2}})
+        );
+    }
+
+    #[test]
+    fn test_default_config_includes_required_and_defaulted_only() {
+        let schema = json!({
+            "type": "object",
+            "required": ["a"],
+            "properties": {
+                "a": {"type": "integer"},
+                "b": {"type": "string", "default": "hi"},
+                "c": {"type": "boolean"},
+            }
+        });
+
+        assert_eq!(default_config(&schema), json!({"a": 0, "b": "hi"}));
+    }
+
+    #[test]
+    fn test_reset_regenerates_defaults_before_merging_layers() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "theme": {"type": "string", "default": "dark"},
+            }
+        });
+        let compiled = JSONSchema::compile(&schema).unwrap();
+
+        let mut store = Store {
+            path: PathBuf::from("tests/config.json"),
+            schema: Some(compiled),
+            schema_value: Some(schema),
+            adapter: Box::new(MemoryAdapter::new()),
+            user_config: json!({"theme": "light"}),
+            layer_values: vec![json!({"theme": "layered"})],
+            config: json!({"theme": "light"}),
+            autosave: false,
+        };
+
+        store.reset(None).unwrap();
+
+        assert_eq!(store.user_config, json!({"theme": "dark"}));
+        // the user layer still wins over the lower layer after reset
+        assert_eq!(store.get(String::from("theme")).unwrap(), "dark");
+    }
 }